@@ -18,11 +18,39 @@ fn semi_missing_diagnostic(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+/// Characters that can continue an expression from the previous line, causing
+/// Automatic Semicolon Insertion to merge two statements together.
+const ASI_CONTINUATION_CHARS: [char; 9] = ['(', '[', '`', '+', '-', '/', '*', '%', ','];
+
+/// Fix-safety guard: would removing the boundary between `before` and `next`
+/// (two adjacent pieces of source with no semicolon between them) change how
+/// the program parses? True when `before` ends with a token that can
+/// continue an expression and `next` opens with an ASI continuation
+/// character, e.g. `before = "var a = b"`, `next = "[1, 2].forEach(fn)"`.
+/// Every deletion fix in this rule must check this before firing, since the
+/// invariant is that a fix never changes re-parsed statement boundaries.
+fn asi_merge_hazard(before: &str, next: &str) -> bool {
+    let Some(next_first_char) = next.trim_start().chars().next() else { return false };
+    if !ASI_CONTINUATION_CHARS.contains(&next_first_char) {
+        return false;
+    }
+    before.trim_end().ends_with(|c: char| c.is_alphanumeric() || matches!(c, ')' | ']' | '}' | '_' | '$'))
+}
+
 #[derive(Debug, Clone)]
 pub struct SemiConfig {
     /// "never" (default): disallow semicolons except where required for ASI
     /// "always": require semicolons
     mode: SemiMode,
+    /// Only consulted in "never" mode. Controls whether a semicolon that
+    /// guards against ASI when the next statement opens with a continuation
+    /// character (see `ASI_CONTINUATION_CHARS`) is required, forbidden, or
+    /// left to the author's discretion.
+    before_statement_continuation_chars: ContinuationCharsPolicy,
+    /// Only consulted in "always" mode. When true, the semicolon on the last
+    /// statement of a block written entirely on one line is not required, and
+    /// one that is present is reported and removed.
+    omit_last_in_one_line_block: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -31,10 +59,28 @@ enum SemiMode {
     Always,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+enum ContinuationCharsPolicy {
+    /// Require the semicolon to avoid the ASI hazard (default).
+    Always,
+    /// Accept the ASI hazard and remove the semicolon anyway.
+    Never,
+    /// Allow either style; never report.
+    Any,
+}
+
+impl Default for ContinuationCharsPolicy {
+    fn default() -> Self {
+        Self::Always
+    }
+}
+
 impl Default for SemiConfig {
     fn default() -> Self {
         Self {
             mode: SemiMode::Never, // Standard Style default
+            before_statement_continuation_chars: ContinuationCharsPolicy::default(),
+            omit_last_in_one_line_block: false,
         }
     }
 }
@@ -76,7 +122,15 @@ declare_oxc_lint!(
     /// ### Options
     ///
     /// - `"never"` (default): Disallow unnecessary semicolons (Standard Style)
+    ///   - `{ "beforeStatementContinuationChars": "always" }` (default): require the
+    ///     semicolon when the next statement opens with a continuation character
+    ///   - `{ "beforeStatementContinuationChars": "never" }`: remove it anyway and
+    ///     accept the ASI hazard
+    ///   - `{ "beforeStatementContinuationChars": "any" }`: allow either style
     /// - `"always"`: Require semicolons at the end of statements
+    ///   - `{ "omitLastInOneLineBlock": true }`: allow (and remove) the semicolon
+    ///     on the last statement of a block written entirely on one line, e.g.
+    ///     `function foo() { return 'bar' }`
     Semi,
     eslint,
     style,
@@ -85,8 +139,9 @@ declare_oxc_lint!(
 
 impl Rule for Semi {
     fn from_configuration(value: Value) -> Self {
-        let mode = value
-            .as_array()
+        let arr = value.as_array();
+
+        let mode = arr
             .and_then(|arr| arr.first())
             .and_then(Value::as_str)
             .map(|s| match s {
@@ -95,7 +150,30 @@ impl Rule for Semi {
             })
             .unwrap_or(SemiMode::Never);
 
-        Self(Box::new(SemiConfig { mode }))
+        let before_statement_continuation_chars = arr
+            .and_then(|arr| arr.get(1))
+            .and_then(Value::as_object)
+            .and_then(|obj| obj.get("beforeStatementContinuationChars"))
+            .and_then(Value::as_str)
+            .map(|s| match s {
+                "never" => ContinuationCharsPolicy::Never,
+                "any" => ContinuationCharsPolicy::Any,
+                _ => ContinuationCharsPolicy::Always,
+            })
+            .unwrap_or_default();
+
+        let omit_last_in_one_line_block = arr
+            .and_then(|arr| arr.get(1))
+            .and_then(Value::as_object)
+            .and_then(|obj| obj.get("omitLastInOneLineBlock"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        Self(Box::new(SemiConfig {
+            mode,
+            before_statement_continuation_chars,
+            omit_last_in_one_line_block,
+        }))
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -137,6 +215,18 @@ impl Rule for Semi {
             AstKind::ExportAllDeclaration(stmt) => {
                 self.check_statement_semi(node, ctx, stmt.span, false);
             }
+            // Stray `;` and runs of `;;;` that are never the required body of a
+            // control-flow statement (those are skipped in `check_empty_statement`).
+            AstKind::EmptyStatement(stmt) => {
+                self.check_empty_statement(node, ctx, stmt.span);
+            }
+            // Class fields: `a = 0;` / `a = 0` and their accessor-property siblings.
+            AstKind::PropertyDefinition(prop) => {
+                self.check_class_member_semi(node, ctx, prop.span);
+            }
+            AstKind::AccessorProperty(prop) => {
+                self.check_class_member_semi(node, ctx, prop.span);
+            }
             _ => {}
         }
     }
@@ -155,9 +245,19 @@ impl Semi {
 
         match self.0.mode {
             SemiMode::Never => {
+                let hazard = self.is_semicolon_required(node, ctx, is_expression_statement);
+
                 if has_semicolon {
-                    // Check if semicolon is required to avoid ASI issues
-                    if !self.is_semicolon_required(node, ctx, is_expression_statement) {
+                    // Under "always" the semicolon guards against ASI, so it stays.
+                    // Under "any" it's the author's call either way, so it also stays.
+                    // Under "never" the author accepts the ASI hazard, so it's removed.
+                    let accepts_hazard =
+                        self.0.before_statement_continuation_chars == ContinuationCharsPolicy::Never;
+                    let keep = hazard && !accepts_hazard;
+                    // Even when our own analysis says it's fine to remove, double-check
+                    // against the raw source right before firing: `accepts_hazard` is an
+                    // explicit opt-in and bypasses the guard, everything else must pass it.
+                    if !keep && (accepts_hazard || self.is_safe_to_remove_statement_semi(node, ctx, span)) {
                         let semi_pos = self.find_semicolon_position(ctx, span);
                         if let Some(semi_span) = semi_pos {
                             ctx.diagnostic_with_fix(
@@ -167,8 +267,11 @@ impl Semi {
                         }
                     }
                 } else {
-                    // Check if missing semicolon could cause ASI issues
-                    if self.is_semicolon_required(node, ctx, is_expression_statement) {
+                    // Only "always" requires adding a semicolon back; "never" and "any"
+                    // are both satisfied by leaving the ASI hazard unaddressed.
+                    let require =
+                        hazard && self.0.before_statement_continuation_chars == ContinuationCharsPolicy::Always;
+                    if require {
                         let end_span = Span::new(span.end, span.end);
                         ctx.diagnostic_with_fix(
                             semi_missing_diagnostic(end_span),
@@ -178,12 +281,21 @@ impl Semi {
                 }
             }
             SemiMode::Always => {
-                if !has_semicolon && self.should_have_semicolon(node, ctx) {
+                let should_have = self.should_have_semicolon(node, ctx);
+                if !has_semicolon && should_have {
                     let end_span = Span::new(span.end, span.end);
                     ctx.diagnostic_with_fix(
                         semi_missing_diagnostic(end_span),
                         |fixer| fixer.insert_text_after_range(span, ";"),
                     );
+                } else if has_semicolon && !should_have {
+                    // `omitLastInOneLineBlock` exempts this semicolon; remove it.
+                    if let Some(semi_span) = self.find_semicolon_position(ctx, span) {
+                        ctx.diagnostic_with_fix(
+                            semi_unnecessary_diagnostic(semi_span),
+                            |fixer| fixer.delete(&semi_span),
+                        );
+                    }
                 }
             }
         }
@@ -202,16 +314,18 @@ impl Semi {
         if let Some(next) = next_node {
             // Check if the next line starts with characters that could cause ASI issues
             let next_source = ctx.source_range(next.span()).trim_start();
-            
-            // ASI issues occur when next line starts with: ( [ ` + - / * %
-            if next_source.starts_with(['(', '[', '`', '+', '-', '/', '*', '%']) {
+
+            // ASI issues occur when next line starts with a continuation character.
+            if next_source.starts_with(ASI_CONTINUATION_CHARS) {
                 // Only required if current statement could be affected
                 if is_expression_statement {
                     return true;
                 }
                 
-                // For other statements, check if they end with something that could be continued
-                let current_source = ctx.source_range(node.span()).trim_end();
+                // For other statements, check if they end with something that could be
+                // continued. Strip a trailing semicolon first: it's the very thing we
+                // might remove, so it must not be mistaken for the statement's last token.
+                let current_source = ctx.source_range(node.span()).trim_end().trim_end_matches(';').trim_end();
                 if current_source.ends_with(|c: char| c.is_alphanumeric() || matches!(c, ')' | ']' | '}' | '_' | '$')) {
                     return true;
                 }
@@ -222,7 +336,7 @@ impl Semi {
     }
 
     /// Check if statement should have semicolon in "always" mode
-    fn should_have_semicolon<'a>(&self, node: &AstNode<'a>, _ctx: &LintContext<'a>) -> bool {
+    fn should_have_semicolon<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
         // Most statements should have semicolons in "always" mode
         // Exceptions: block statements, function declarations, etc.
         match node.kind() {
@@ -236,9 +350,34 @@ impl Semi {
             | AstKind::ForOfStatement(_)
             | AstKind::DoWhileStatement(_)
             | AstKind::TryStatement(_)
-            | AstKind::SwitchStatement(_) => false,
-            _ => true,
+            | AstKind::SwitchStatement(_) => return false,
+            _ => {}
+        }
+
+        if self.0.omit_last_in_one_line_block && self.is_last_in_one_line_block(node, ctx) {
+            return false;
         }
+
+        true
+    }
+
+    /// Whether `node` is the last statement of a block whose opening and
+    /// closing braces sit on the same source line, e.g. the `return` in
+    /// `function foo() { return 'bar' }`. A function/arrow/method body is
+    /// `AstKind::FunctionBody`, not `BlockStatement`, so both are checked.
+    fn is_last_in_one_line_block<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+        if self.get_next_statement(node, ctx).is_some() {
+            return false;
+        }
+
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else { return false };
+        let block_span = match parent.kind() {
+            AstKind::BlockStatement(block) => block.span,
+            AstKind::FunctionBody(body) => body.span,
+            _ => return false,
+        };
+
+        !ctx.source_range(block_span).contains('\n')
     }
 
     /// Find the position of the semicolon in the source
@@ -265,6 +404,11 @@ impl Semi {
                 AstKind::BlockStatement(block) => {
                     return self.find_next_in_body(&block.body, node, ctx);
                 }
+                // A function/arrow/method body is its own statement-list container
+                // distinct from `BlockStatement`; its field is `statements`, not `body`.
+                AstKind::FunctionBody(body) => {
+                    return self.find_next_in_body(&body.statements, node, ctx);
+                }
                 _ => {
                     current = parent;
                 }
@@ -303,6 +447,181 @@ impl Semi {
         
         None
     }
+
+    /// Handle a stray `;` (`AstKind::EmptyStatement`). Only statement-list
+    /// positions (Program body, block body, function body, switch-case consequents) are
+    /// ever redundant; an empty statement that is the single required body
+    /// of a control-flow construct (`for (;;) ;`, `if (x) ;`, a labelled
+    /// statement, ...) changes the program's meaning if removed, so those
+    /// are left alone by simply not matching any arm below.
+    fn check_empty_statement<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, span: Span) {
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else { return };
+
+        let body: &'a oxc_allocator::Vec<Statement<'a>> = match parent.kind() {
+            AstKind::Program(program) => &program.body,
+            AstKind::BlockStatement(block) => &block.body,
+            AstKind::FunctionBody(body) => &body.statements,
+            AstKind::SwitchCase(case) => &case.consequent,
+            _ => return,
+        };
+
+        let Some(index) = body.iter().position(|stmt| stmt.span() == span) else { return };
+
+        // Only report once per maximal run: skip if the previous statement is
+        // also empty, since that earlier statement's visit already covers us.
+        if index > 0 && matches!(body[index - 1], Statement::EmptyStatement(_)) {
+            return;
+        }
+
+        let mut end_index = index;
+        while end_index + 1 < body.len() && matches!(body[end_index + 1], Statement::EmptyStatement(_)) {
+            end_index += 1;
+        }
+
+        // Fix-safety guard: if the statement before the run and the statement
+        // after it would merge with no semicolon between them, keep the last
+        // semicolon of the run instead of deleting it all. In "never" mode the
+        // previous statement's own trailing `;` is left out of this check, since
+        // the statement check may remove it in the same pass (e.g. the `var`'s
+        // `;` in `var a = b\n;;\n[1, 2].forEach(fn)`).
+        let must_keep_one = index > 0 && end_index + 1 < body.len() && {
+            let before = ctx.source_range(body[index - 1].span());
+            let before =
+                if self.0.mode == SemiMode::Never { before.trim_end().trim_end_matches(';') } else { before };
+            asi_merge_hazard(before, ctx.source_range(body[end_index + 1].span()))
+        };
+
+        if must_keep_one {
+            if end_index == index {
+                // The run is exactly the one semicolon guarding against ASI; nothing to remove.
+                return;
+            }
+            end_index -= 1;
+        }
+
+        let run_span = Span::new(body[index].span().start, body[end_index].span().end);
+        ctx.diagnostic_with_fix(semi_unnecessary_diagnostic(run_span), |fixer| fixer.delete(&run_span));
+    }
+
+    /// Check a class field/accessor-property's trailing semicolon. Class
+    /// members follow different ASI rules than statements: a `;` after a
+    /// field is usually redundant, but it's required when the *next* member
+    /// starts with a continuation character (such as `[`), or when the current
+    /// member is itself a bare `get`, `set`, `static` or `async` field, which
+    /// would otherwise fuse with the next member and be reparsed as its modifier.
+    fn check_class_member_semi<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, span: Span) {
+        let semi_span = self.find_class_member_semicolon(ctx, span);
+        let has_semicolon = semi_span.is_some();
+
+        match self.0.mode {
+            SemiMode::Never => {
+                if has_semicolon
+                    && !self.class_member_semi_required(node, ctx, span)
+                    && self.is_safe_to_remove_class_member_semi(node, ctx, span)
+                {
+                    if let Some(semi_span) = semi_span {
+                        ctx.diagnostic_with_fix(
+                            semi_unnecessary_diagnostic(semi_span),
+                            |fixer| fixer.delete(&semi_span),
+                        );
+                    }
+                }
+            }
+            SemiMode::Always => {
+                if !has_semicolon {
+                    let end_span = Span::new(span.end, span.end);
+                    ctx.diagnostic_with_fix(
+                        semi_missing_diagnostic(end_span),
+                        |fixer| fixer.insert_text_after_range(span, ";"),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Locate a class member's trailing semicolon, if any. Unlike statements,
+    /// `PropertyDefinition`/`AccessorProperty` spans aren't guaranteed to
+    /// include a trailing `;` — it may sit just past `span.end`. Check inside
+    /// the span first, then fall back to scanning the source immediately
+    /// after it, skipping only whitespace.
+    fn find_class_member_semicolon(&self, ctx: &LintContext, span: Span) -> Option<Span> {
+        if let Some(semi_span) = self.find_semicolon_position(ctx, span) {
+            return Some(semi_span);
+        }
+
+        let rest = ctx.source_text().get(span.end as usize..)?;
+        let offset = rest.find(|c: char| !c.is_whitespace())?;
+        if rest[offset..].starts_with(';') {
+            let semi_start = span.end + u32::try_from(offset).ok()?;
+            Some(Span::new(semi_start, semi_start + 1))
+        } else {
+            None
+        }
+    }
+
+    /// Bare field names that, with no semicolon after them, read as a prefix
+    /// modifier for whatever class member follows (`get; x(){}` would
+    /// reparse as the getter `x`, not a field `get` plus a method `x`).
+    const CLASS_MEMBER_MODIFIER_KEYWORDS: [&'static str; 4] = ["get", "set", "static", "async"];
+
+    /// Whether this member's own source, with its own trailing `;` removed,
+    /// is exactly one of the bare modifier keywords that fuses with
+    /// whatever member comes next.
+    fn is_bare_modifier_keyword(ctx: &LintContext, span: Span) -> bool {
+        let current_source = ctx.source_range(span).trim_end().trim_end_matches(';').trim_end();
+        Self::CLASS_MEMBER_MODIFIER_KEYWORDS.contains(&current_source)
+    }
+
+    fn class_member_semi_required<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, span: Span) -> bool {
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else { return false };
+        let AstKind::ClassBody(class_body) = parent.kind() else { return false };
+
+        let Some(index) = class_body.body.iter().position(|el| el.span() == span) else { return false };
+        let Some(next) = class_body.body.get(index + 1) else { return false };
+
+        let next_source = ctx.source_range(next.span()).trim_start();
+
+        if next_source.starts_with(ASI_CONTINUATION_CHARS) {
+            return true;
+        }
+
+        // `get`/`set`/`static`/`async` read bare (no value, just the key) fuse
+        // with whatever member follows, regardless of what that member starts with.
+        if Self::is_bare_modifier_keyword(ctx, span) {
+            return true;
+        }
+
+        false
+    }
+
+    /// Fix-safety guard for statement semicolon deletion: re-derives the ASI
+    /// hazard straight from source immediately before the fix fires, so a
+    /// mistake (or future regression) in `is_semicolon_required` can't turn
+    /// into a broken fix.
+    fn is_safe_to_remove_statement_semi<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, span: Span) -> bool {
+        let Some(next) = self.get_next_statement(node, ctx) else { return true };
+        let before = ctx.source_range(span).trim_end().trim_end_matches(';');
+        !asi_merge_hazard(before, ctx.source_range(next.span()))
+    }
+
+    /// Same guard as `is_safe_to_remove_statement_semi`, for class members.
+    /// Re-derives both hazards straight from source right before the fix
+    /// fires, so a mistake in `class_member_semi_required` can't turn into a
+    /// broken fix either.
+    fn is_safe_to_remove_class_member_semi<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, span: Span) -> bool {
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else { return true };
+        let AstKind::ClassBody(class_body) = parent.kind() else { return true };
+        let Some(index) = class_body.body.iter().position(|el| el.span() == span) else { return true };
+        let Some(next) = class_body.body.get(index + 1) else { return true };
+
+        // A bare modifier keyword only fuses with a member that follows it.
+        if Self::is_bare_modifier_keyword(ctx, span) {
+            return false;
+        }
+
+        let before = ctx.source_range(span).trim_end().trim_end_matches(';');
+        !asi_merge_hazard(before, ctx.source_range(next.span()))
+    }
 }
 
 #[test]
@@ -321,11 +640,66 @@ fn test() {
         ("var a = b\n;(function() {})()", None),
         ("var a = b\n;[1, 2, 3].forEach(fn)", None),
         ("var a = b\n;`template`", None),
-        
+
+        // Fix-safety: this semicolon guards against ASI (the statement ends
+        // in an alphanumeric and the next one opens with `[`), so it must not
+        // be reported as unnecessary even though it's not preceded by `;`.
+        ("var a = b;\n[1, 2].forEach(fn)", None),
+
+        // Fix-safety: a lone guarding `;` on its own line is parsed as the end
+        // of the `var` statement, and it must survive like any guarding `;`.
+        ("var a = b\n;\n[1, 2].forEach(fn)", None),
+
         // Always mode - correct cases
         ("var a = b;", Some(json!(["always"]))),
         ("var a = b;\nvar c = d;", Some(json!(["always"]))),
         ("function foo() { return 'bar'; }", Some(json!(["always"]))),
+
+        // beforeStatementContinuationChars: "never" accepts the ASI hazard
+        (
+            "var a = b\n(function() {})()",
+            Some(json!(["never", { "beforeStatementContinuationChars": "never" }])),
+        ),
+        // beforeStatementContinuationChars: "any" allows either style
+        (
+            "var a = b\n;(function() {})()",
+            Some(json!(["never", { "beforeStatementContinuationChars": "any" }])),
+        ),
+        (
+            "var a = b\n(function() {})()",
+            Some(json!(["never", { "beforeStatementContinuationChars": "any" }])),
+        ),
+
+        // Empty statements that are the required body of a control-flow
+        // construct are not redundant and must be left alone.
+        ("for (;;) ;", None),
+        ("for (const x in y) ;", None),
+        ("for (const x of y) ;", None),
+        ("while (x) ;", None),
+        ("do ; while (x)", None),
+        ("if (x) ;", None),
+        ("label: ;", None),
+
+        // omitLastInOneLineBlock: the one-line block's last statement is exempt
+        (
+            "function foo() { return 'bar' }",
+            Some(json!(["always", { "omitLastInOneLineBlock": true }])),
+        ),
+        (
+            "function foo() {\n  var a = b;\n  return 'bar';\n}",
+            Some(json!(["always", { "omitLastInOneLineBlock": true }])),
+        ),
+
+        // Class fields: redundant semicolon removed
+        ("class Foo {\n  a = 0\n  b = 1\n}", None),
+        // ... unless the next member would otherwise fuse with this one
+        ("class Foo {\n  a = 0\n  ;[x]()\n}", None),
+        ("class Foo {\n  get\n  ;async x() {}\n}", None),
+        // ... and a bare `get`/`set`/`static`/`async` field fuses with
+        // *whatever* member comes next, not just one that starts with a keyword
+        ("class Foo {\n  get;\n  x() {}\n}", None),
+        // always mode requires the semicolon
+        ("class Foo {\n  a = 0;\n  b = 1;\n}", Some(json!(["always"]))),
     ];
 
     let fail = vec![
@@ -341,19 +715,91 @@ fn test() {
         // Always mode - missing semicolons
         ("var a = b", Some(json!(["always"]))),
         ("const message = 'Hello'", Some(json!(["always"]))),
+
+        // beforeStatementContinuationChars: "never" still flags the now-unnecessary semicolon
+        (
+            "var a = b\n;(function() {})()",
+            Some(json!(["never", { "beforeStatementContinuationChars": "never" }])),
+        ),
+
+        // Redundant empty statements
+        (";", None),
+        (";;;", None),
+        ("var a = b;\n;", None),
+        ("function foo() { ;; }", None),
+
+        // omitLastInOneLineBlock: the semicolon on the last statement is removed
+        (
+            "function foo() { return 'bar'; }",
+            Some(json!(["always", { "omitLastInOneLineBlock": true }])),
+        ),
+
+        // Class fields: unnecessary semicolon
+        ("class Foo {\n  a = 0;\n  b = 1\n}", None),
+        // Class fields: always mode requires the semicolon
+        ("class Foo {\n  a = 0\n  b = 1\n}", Some(json!(["always"]))),
+        // A bare modifier-keyword field with no following member can't fuse
+        // with anything, so its semicolon is still redundant
+        ("class Foo {\n  get;\n}", None),
+
+        // Fix-safety: a run of guarding semicolons is trimmed down to one, not deleted outright
+        ("var a = b\n;;\n[1, 2].forEach(fn)", None),
     ];
 
     let fix = vec![
         // Remove unnecessary semicolons
         ("var a = b;", "var a = b", None),
         ("const message = 'Hello';", "const message = 'Hello'", None),
-        
+
         // Add required semicolons
         ("var a = b\n(function() {})()", "var a = b;\n(function() {})()", None),
         ("var a = b\n[1, 2, 3].forEach(fn)", "var a = b;\n[1, 2, 3].forEach(fn)", None),
-        
+
         // Always mode fixes
         ("var a = b", "var a = b;", Some(json!(["always"]))),
+
+        // beforeStatementContinuationChars: "never" removes the guarding semicolon
+        (
+            "var a = b\n;(function() {})()",
+            "var a = b\n(function() {})()",
+            Some(json!(["never", { "beforeStatementContinuationChars": "never" }])),
+        ),
+
+        // Redundant empty statements are collapsed in a single edit
+        (";", "", None),
+        (";;;", "", None),
+        ("function foo() { ;; }", "function foo() {  }", None),
+
+        // omitLastInOneLineBlock
+        (
+            "function foo() { return 'bar'; }",
+            "function foo() { return 'bar' }",
+            Some(json!(["always", { "omitLastInOneLineBlock": true }])),
+        ),
+
+        // Class fields
+        (
+            "class Foo {\n  a = 0;\n  b = 1\n}",
+            "class Foo {\n  a = 0\n  b = 1\n}",
+            None,
+        ),
+        (
+            "class Foo {\n  a = 0\n  b = 1\n}",
+            "class Foo {\n  a = 0;\n  b = 1;\n}",
+            Some(json!(["always"])),
+        ),
+        (
+            "class Foo {\n  get;\n}",
+            "class Foo {\n  get\n}",
+            None,
+        ),
+
+        // Fix-safety: trims the run down to the one semicolon ASI still needs
+        (
+            "var a = b\n;;\n[1, 2].forEach(fn)",
+            "var a = b\n;\n[1, 2].forEach(fn)",
+            None,
+        ),
     ];
 
     Tester::new(Semi::NAME, Semi::PLUGIN, pass, fail)